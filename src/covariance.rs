@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+pub type Id = u64;
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Mints a fresh, monotonically increasing id for a `Measure`.
+pub fn next_id() -> Id {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+fn key(a: Id, b: Id) -> (Id, Id) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Tracks how a `Measure`'s value depends on the root measurements that fed
+/// into it, so that reusing the same root more than once in an expression
+/// (e.g. `x / (x + y)`) is recognised as correlated instead of being treated
+/// as two independent quantities.
+#[derive(Clone, Debug, Default)]
+pub struct Covariance {
+    /// Cov(root_i, root_j) keyed by sorted id pair. Only the (id, id) variance
+    /// of each root is stored; a pair with no entry counts as uncorrelated.
+    entries: HashMap<(Id, Id), f64>,
+    /// d(value)/d(root_i) at the current linearization point.
+    coeffs: HashMap<Id, f64>,
+}
+
+impl Covariance {
+    /// A fresh leaf: a root measurement that only depends on itself.
+    pub fn root(id: Id, variance: f64) -> Self {
+        let mut entries = HashMap::new();
+        entries.insert(key(id, id), variance);
+        let mut coeffs = HashMap::new();
+        coeffs.insert(id, 1.0);
+        Self { entries, coeffs }
+    }
+
+    /// Linearizes `a_coeff * a + b_coeff * b` around the current point,
+    /// merging the covariance structure of both operands. `Add`/`Sub` pass
+    /// constant coefficients of `1.0`/`-1.0`; `Mul`/`Div` pass the partials
+    /// from the product/quotient rule evaluated at the operands' values.
+    pub fn combine_linear(a: &Covariance, a_coeff: f64, b: &Covariance, b_coeff: f64) -> Covariance {
+        let mut entries = a.entries.clone();
+        for (&k, &v) in &b.entries {
+            entries.entry(k).or_insert(v);
+        }
+
+        let mut coeffs: HashMap<Id, f64> = HashMap::new();
+        for (&id, &c) in &a.coeffs {
+            *coeffs.entry(id).or_insert(0.0) += a_coeff * c;
+        }
+        for (&id, &c) in &b.coeffs {
+            *coeffs.entry(id).or_insert(0.0) += b_coeff * c;
+        }
+
+        Self { entries, coeffs }
+    }
+
+    /// Collapses `Var(f) = sum_i sum_j (df/dxi)(df/dxj) Cov(xi, xj)` using
+    /// the tracked sensitivities and root covariances.
+    pub fn variance(&self) -> f64 {
+        let mut total = 0.0;
+        for (&i, &ci) in &self.coeffs {
+            for (&j, &cj) in &self.coeffs {
+                let cov = self.entries.get(&key(i, j)).copied().unwrap_or(0.0);
+                total += ci * cj * cov;
+            }
+        }
+        total.max(0.0)
+    }
+}
@@ -0,0 +1,189 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use rand::{Rng, RngCore};
+
+use crate::covariance::{next_id, Covariance};
+use crate::measure::Measure;
+use crate::uncertainty::Uncertainty;
+
+/// A lazily-evaluated random variable. `sample` is called once per Monte-Carlo
+/// `epoch`; implementors that sit at the leaves of a sampler tree are expected
+/// to cache their draw for the epoch so that a leaf reused further up the tree
+/// (e.g. via `Sampler::join(&x, &x, ..)`) stays correlated with itself instead
+/// of being redrawn independently.
+pub trait Distribution {
+    fn sample(&self, rng: &mut dyn RngCore, epoch: usize) -> f64;
+}
+
+struct NormalLeaf {
+    mean: f64,
+    std: f64,
+    cache: Cell<Option<(usize, f64)>>,
+}
+
+impl Distribution for NormalLeaf {
+    fn sample(&self, rng: &mut dyn RngCore, epoch: usize) -> f64 {
+        if let Some((cached_epoch, value)) = self.cache.get() {
+            if cached_epoch == epoch {
+                return value;
+            }
+        }
+        let value = self.mean + self.std * standard_normal(rng);
+        self.cache.set(Some((epoch, value)));
+        value
+    }
+}
+
+fn standard_normal(rng: &mut dyn RngCore) -> f64 {
+    // Box-Muller transform; avoids pulling in a distributions crate for a
+    // single Gaussian draw.
+    let u1: f64 = rng.gen_range(f64::MIN_POSITIVE..1.0);
+    let u2: f64 = rng.gen::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+}
+
+struct Map<F> {
+    source: Sampler,
+    f: F,
+}
+
+impl<F: Fn(f64) -> f64> Distribution for Map<F> {
+    fn sample(&self, rng: &mut dyn RngCore, epoch: usize) -> f64 {
+        (self.f)(self.source.0.sample(rng, epoch))
+    }
+}
+
+struct Join<F> {
+    a: Sampler,
+    b: Sampler,
+    f: F,
+}
+
+impl<F: Fn(f64, f64) -> f64> Distribution for Join<F> {
+    fn sample(&self, rng: &mut dyn RngCore, epoch: usize) -> f64 {
+        (self.f)(self.a.0.sample(rng, epoch), self.b.0.sample(rng, epoch))
+    }
+}
+
+/// A node in a lazy sampler tree, built up from `Measure`s via `map`/`join`
+/// and collapsed back into a `Measure` with `estimate`. Cloning a `Sampler`
+/// shares the same underlying node, so the same leaf sampled twice within one
+/// `estimate` call draws once per epoch and correlates across occurrences.
+#[derive(Clone)]
+pub struct Sampler(Rc<dyn Distribution>);
+
+impl Sampler {
+    pub fn from_measure(measure: &Measure) -> Self {
+        let abs = measure.uncertainty.to_absolute(measure.value);
+        let std = ((abs.low + abs.high) / 2.0).max(0.0);
+        Sampler(Rc::new(NormalLeaf {
+            mean: measure.value,
+            std,
+            cache: Cell::new(None),
+        }))
+    }
+
+    pub fn map(&self, f: impl Fn(f64) -> f64 + 'static) -> Sampler {
+        Sampler(Rc::new(Map {
+            source: self.clone(),
+            f,
+        }))
+    }
+
+    pub fn join(&self, other: &Sampler, f: impl Fn(f64, f64) -> f64 + 'static) -> Sampler {
+        Sampler(Rc::new(Join {
+            a: self.clone(),
+            b: other.clone(),
+            f,
+        }))
+    }
+
+    /// Draws `n_samples` epochs through the sampler tree, accumulating mean
+    /// and variance online via Welford's algorithm, and returns a `Measure`
+    /// whose uncertainty is the empirical 1-sigma interval around the mean.
+    pub fn estimate(&self, n_samples: usize) -> Measure {
+        assert!(n_samples > 0, "estimate requires at least one sample");
+        let mut rng = rand::thread_rng();
+
+        let mut mean = 0.0_f64;
+        let mut m2 = 0.0_f64;
+        let mut samples = Vec::with_capacity(n_samples);
+
+        for epoch in 0..n_samples {
+            let x = self.0.sample(&mut rng, epoch);
+            let count = (epoch + 1) as f64;
+            let delta = x - mean;
+            mean += delta / count;
+            m2 += delta * (x - mean);
+            samples.push(x);
+        }
+
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let low = mean - quantile(&samples, 0.1587);
+        let high = quantile(&samples, 0.8413) - mean;
+        let variance = if n_samples > 1 {
+            m2 / (n_samples - 1) as f64
+        } else {
+            0.0
+        };
+
+        let id = next_id();
+        Measure {
+            id,
+            value: mean,
+            uncertainty: Uncertainty::non_symmetric(low.max(0.0), high.max(0.0)),
+            covariance: Covariance::root(id, variance),
+        }
+    }
+}
+
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    if sorted.len() == 1 {
+        return sorted[0];
+    }
+    let pos = q * (sorted.len() - 1) as f64;
+    let lower = pos.floor() as usize;
+    let upper = pos.ceil() as usize;
+    if lower == upper {
+        sorted[lower]
+    } else {
+        let frac = pos - lower as f64;
+        sorted[lower] + (sorted[upper] - sorted[lower]) * frac
+    }
+}
+
+impl Measure {
+    pub fn sampler(&self) -> Sampler {
+        Sampler::from_measure(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimate_recovers_mean_and_std() {
+        let x = Measure::scalar(10.0).with_abs_err(2.0);
+        let estimate = x.sampler().estimate(20_000);
+
+        assert!((estimate.value - 10.0).abs() < 0.2);
+        let abs = estimate.uncertainty.to_absolute(estimate.value);
+        assert!((abs.low - 2.0).abs() < 0.3);
+        assert!((abs.high - 2.0).abs() < 0.3);
+    }
+
+    #[test]
+    fn reused_leaf_cancels_via_epoch_cache() {
+        let x = Measure::scalar(5.0).with_abs_err(1.0);
+        let sampler = x.sampler();
+        let difference = sampler.join(&sampler, |a, b| a - b);
+        let estimate = difference.estimate(1_000);
+
+        assert_eq!(estimate.value, 0.0);
+        let abs = estimate.uncertainty.to_absolute(estimate.value);
+        assert_eq!(abs.low, 0.0);
+        assert_eq!(abs.high, 0.0);
+    }
+}
@@ -0,0 +1,190 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::covariance::{next_id, Covariance};
+use crate::measure::Measure;
+use crate::uncertainty::Uncertainty;
+
+/// A forward-mode dual number: `value` carries the quantity and `deriv`
+/// carries its derivative with respect to whatever single variable is being
+/// differentiated. Operator overloads apply the usual differentiation rules
+/// so that pushing a `Measure` through a closure built from `+`/`*`/`sin`/...
+/// yields both the function's value and its derivative in one pass.
+#[derive(Copy, Clone, Debug)]
+pub struct Dual {
+    pub value: f64,
+    pub deriv: f64,
+}
+
+impl Dual {
+    pub fn variable(value: f64) -> Self {
+        Self { value, deriv: 1.0 }
+    }
+
+    pub fn constant(value: f64) -> Self {
+        Self { value, deriv: 0.0 }
+    }
+
+    pub fn sin(self) -> Self {
+        Self {
+            value: self.value.sin(),
+            deriv: self.value.cos() * self.deriv,
+        }
+    }
+
+    pub fn cos(self) -> Self {
+        Self {
+            value: self.value.cos(),
+            deriv: -self.value.sin() * self.deriv,
+        }
+    }
+
+    pub fn exp(self) -> Self {
+        let value = self.value.exp();
+        Self {
+            value,
+            deriv: value * self.deriv,
+        }
+    }
+
+    pub fn ln(self) -> Self {
+        Self {
+            value: self.value.ln(),
+            deriv: self.deriv / self.value,
+        }
+    }
+
+    pub fn sqrt(self) -> Self {
+        let value = self.value.sqrt();
+        Self {
+            value,
+            deriv: self.deriv / (2.0 * value),
+        }
+    }
+
+    pub fn powf(self, n: f64) -> Self {
+        Self {
+            value: self.value.powf(n),
+            deriv: n * self.value.powf(n - 1.0) * self.deriv,
+        }
+    }
+}
+
+impl Add for Dual {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            value: self.value + rhs.value,
+            deriv: self.deriv + rhs.deriv,
+        }
+    }
+}
+
+impl Sub for Dual {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            value: self.value - rhs.value,
+            deriv: self.deriv - rhs.deriv,
+        }
+    }
+}
+
+impl Mul for Dual {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            value: self.value * rhs.value,
+            deriv: self.deriv * rhs.value + self.value * rhs.deriv,
+        }
+    }
+}
+
+impl Div for Dual {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        Self {
+            value: self.value / rhs.value,
+            deriv: (self.deriv * rhs.value - self.value * rhs.deriv) / (rhs.value * rhs.value),
+        }
+    }
+}
+
+impl Neg for Dual {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            value: -self.value,
+            deriv: -self.deriv,
+        }
+    }
+}
+
+impl Measure {
+    /// Pushes this measurement through an arbitrary differentiable function,
+    /// propagating error by the first-order rule
+    /// `new_uncertainty_abs = |f'(value)| * uncertainty_abs`.
+    pub fn apply(self, f: impl Fn(Dual) -> Dual) -> Measure {
+        let result = f(Dual::variable(self.value));
+        let slope = result.deriv.abs();
+        let abs = self.uncertainty.to_absolute(self.value);
+        // For a decreasing function (negative derivative), the input's low
+        // excursion pushes the output up and the input's high excursion
+        // pushes it down, so low/high swap sides.
+        let (out_low, out_high) = if result.deriv >= 0.0 {
+            (slope * abs.low, slope * abs.high)
+        } else {
+            (slope * abs.high, slope * abs.low)
+        };
+        Measure {
+            id: next_id(),
+            value: result.value,
+            uncertainty: Uncertainty::non_symmetric(out_low, out_high).with_precision(abs.precision),
+            covariance: Covariance::combine_linear(
+                &self.covariance,
+                result.deriv,
+                &Covariance::default(),
+                0.0,
+            ),
+        }
+    }
+
+    pub fn sin(self) -> Measure {
+        self.apply(Dual::sin)
+    }
+
+    pub fn cos(self) -> Measure {
+        self.apply(Dual::cos)
+    }
+
+    pub fn exp(self) -> Measure {
+        self.apply(Dual::exp)
+    }
+
+    pub fn ln(self) -> Measure {
+        self.apply(Dual::ln)
+    }
+
+    pub fn sqrt(self) -> Measure {
+        self.apply(Dual::sqrt)
+    }
+
+    pub fn powf(self, n: f64) -> Measure {
+        self.apply(move |d| d.powf(n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uncertainty::Uncertainty;
+
+    #[test]
+    fn decreasing_function_swaps_low_and_high() {
+        let x = Measure::scalar(1.0).with_uncertainty(Uncertainty::non_symmetric(0.05, 0.2));
+        let result = x.cos();
+
+        let abs = result.uncertainty.to_absolute(result.value);
+        assert!((abs.low - 0.168).abs() < 1e-2, "low was {}", abs.low);
+        assert!((abs.high - 0.042).abs() < 1e-2, "high was {}", abs.high);
+    }
+}
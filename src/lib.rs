@@ -0,0 +1,6 @@
+pub mod covariance;
+pub mod distribution;
+pub mod dual;
+pub mod measure;
+pub mod parse;
+pub mod uncertainty;
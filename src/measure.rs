@@ -0,0 +1,291 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::covariance::{next_id, Covariance, Id};
+use crate::uncertainty::Uncertainty;
+
+/// A reused `Measure` (e.g. `x.clone()` appearing twice in an expression)
+/// keeps the same `id`, so the covariance machinery in
+/// [`crate::covariance`] recognises it as the same root instead of an
+/// independent duplicate.
+#[derive(Clone)]
+pub struct Measure {
+    pub id: Id,
+    pub value: f64,
+    pub uncertainty: Uncertainty,
+    pub covariance: Covariance,
+}
+
+impl Measure {
+    pub fn scalar(value: f64) -> Measure {
+        assert!(!value.is_nan(), "Measure value must not be NaN");
+        let id = next_id();
+        Measure {
+            id,
+            value,
+            uncertainty: Uncertainty::null(),
+            covariance: Covariance::root(id, 0.0),
+        }
+    }
+
+    pub fn with_rel_err(self, err: f64) -> Measure {
+        assert!(
+            !err.is_nan() && err >= 0.,
+            "relative uncertainty must be a non-negative, non-NaN number, got {err}"
+        );
+        self.with_uncertainty(Uncertainty::symmetric_rel(err))
+    }
+
+    pub fn with_abs_err(self, err: f64) -> Measure {
+        assert!(
+            !err.is_nan() && err >= 0.,
+            "absolute uncertainty must be a non-negative, non-NaN number, got {err}"
+        );
+        self.with_uncertainty(Uncertainty::symmetric_abs(err))
+    }
+
+    /// Attaches an already-built `Uncertainty` (symmetric or asymmetric) to
+    /// this measurement, re-seeding the covariance tracking as its own root.
+    pub fn with_uncertainty(self, uncertainty: Uncertainty) -> Measure {
+        assert!(
+            !uncertainty.low.is_nan() && uncertainty.low >= 0.,
+            "uncertainty.low must be a non-negative, non-NaN number, got {}",
+            uncertainty.low
+        );
+        assert!(
+            !uncertainty.high.is_nan() && uncertainty.high >= 0.,
+            "uncertainty.high must be a non-negative, non-NaN number, got {}",
+            uncertainty.high
+        );
+        let variance = Self::variance_from(uncertainty, self.value);
+        Measure {
+            id: self.id,
+            value: self.value,
+            uncertainty,
+            covariance: Covariance::root(self.id, variance),
+        }
+    }
+
+    /// Uncertainty-free constant for building expressions without having to
+    /// thread a scalar error through by hand.
+    pub fn pi() -> Measure {
+        Measure::scalar(std::f64::consts::PI)
+    }
+
+    pub fn tau() -> Measure {
+        Measure::scalar(std::f64::consts::TAU)
+    }
+
+    pub fn zero() -> Measure {
+        Measure::scalar(0.0)
+    }
+
+    pub fn one() -> Measure {
+        Measure::scalar(1.0)
+    }
+
+    pub fn with_precision(&self, precision: usize) -> Self {
+        Self {
+            id: self.id,
+            value: self.value,
+            uncertainty: self.uncertainty.with_precision(precision),
+            covariance: self.covariance.clone(),
+        }
+    }
+
+    pub fn resolve_high_low_limits(&self) -> (f64, f64) {
+        let unc = self.uncertainty.to_absolute(self.value);
+        (self.value + unc.high, self.value - unc.low)
+    }
+
+    /// Collapses the tracked root-covariance structure into an `Uncertainty`,
+    /// correctly discounting the error contributed by a root measurement
+    /// reused more than once in the same expression.
+    pub fn covariance_uncertainty(&self) -> Uncertainty {
+        let std = self.covariance.variance().sqrt();
+        Uncertainty::symmetric_abs(std)
+    }
+
+    fn variance_from(uncertainty: Uncertainty, value: f64) -> f64 {
+        let abs = uncertainty.to_absolute(value);
+        let std = (abs.low + abs.high) / 2.0;
+        std * std
+    }
+
+    /// Width of the propagated interval, used as the tie-breaker for `Ord`.
+    fn uncertainty_width(&self) -> f64 {
+        let (high, low) = self.resolve_high_low_limits();
+        high - low
+    }
+
+    /// Shared by `Eq`/`Hash`/`Ord` so the three stay consistent: -0.0 is
+    /// folded into 0.0 so that bitwise-distinct zeros still compare equal.
+    fn normalized_components(&self) -> (f64, f64) {
+        let normalize = |x: f64| if x == 0.0 { 0.0 } else { x };
+        (normalize(self.value), normalize(self.uncertainty_width()))
+    }
+
+    fn ordering_key(&self) -> (u64, u64) {
+        let (value, width) = self.normalized_components();
+        (value.to_bits(), width.to_bits())
+    }
+}
+
+impl PartialEq for Measure {
+    fn eq(&self, other: &Self) -> bool {
+        self.ordering_key() == other.ordering_key()
+    }
+}
+
+impl Eq for Measure {}
+
+impl PartialOrd for Measure {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Measure {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Compares the same normalized components `ordering_key` hashes, so
+        // `Eq`, `Ord`, and `Hash` all agree on `-0.0` vs `0.0`. `total_cmp`
+        // rather than `partial_cmp().unwrap()`: the NaN guard only holds at
+        // the constructors, not through `Add`/`Sub`/`Mul`/`Div` (e.g.
+        // `0.0 / 0.0`), so sorting a `Vec<Measure>` must not be able to panic.
+        let (value, width) = self.normalized_components();
+        let (other_value, other_width) = other.normalized_components();
+        value
+            .total_cmp(&other_value)
+            .then_with(|| width.total_cmp(&other_width))
+    }
+}
+
+impl Hash for Measure {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ordering_key().hash(state);
+    }
+}
+
+impl Display for Measure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (high, low) = self.resolve_high_low_limits();
+        write!(
+            f,
+            "{:>pad$.precision$} {:>pad$.precision$} \n\t:= ({:>pad$.precision$}, {:>pad$.precision$})",
+            self.value,
+            self.uncertainty,
+            low,
+            high,
+            precision = self.uncertainty.precision,
+            pad = 0
+        )
+    }
+}
+
+impl Add for Measure {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            id: next_id(),
+            value: self.value + rhs.value,
+            uncertainty: self.uncertainty.to_absolute(self.value)
+                + rhs.uncertainty.to_absolute(rhs.value),
+            covariance: Covariance::combine_linear(&self.covariance, 1.0, &rhs.covariance, 1.0),
+        }
+    }
+}
+
+impl Sub for Measure {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            id: next_id(),
+            value: self.value - rhs.value,
+            uncertainty: self.uncertainty.to_absolute(self.value)
+                + rhs.uncertainty.to_absolute(rhs.value),
+            covariance: Covariance::combine_linear(&self.covariance, 1.0, &rhs.covariance, -1.0),
+        }
+    }
+}
+
+impl Div for Measure {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self {
+            id: next_id(),
+            value: self.value / rhs.value,
+            uncertainty: self.uncertainty.to_relative(self.value)
+                * rhs.uncertainty.to_relative(rhs.value),
+            covariance: Covariance::combine_linear(
+                &self.covariance,
+                1.0 / rhs.value,
+                &rhs.covariance,
+                -self.value / (rhs.value * rhs.value),
+            ),
+        }
+    }
+}
+
+impl Mul for Measure {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            id: next_id(),
+            value: self.value * rhs.value,
+            uncertainty: self.uncertainty.to_relative(self.value)
+                * rhs.uncertainty.to_relative(rhs.value),
+            covariance: Covariance::combine_linear(&self.covariance, rhs.value, &rhs.covariance, self.value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reused_measurement_cancels_correlated_error() {
+        let x = Measure::scalar(10.0).with_abs_err(1.0);
+        let y = Measure::scalar(5.0).with_abs_err(1.0);
+
+        let naive = x.clone() / (x.clone() + y);
+        let correlated = naive.covariance_uncertainty();
+
+        let naive_abs = naive.uncertainty.to_absolute(naive.value);
+        let naive_width = naive_abs.low + naive_abs.high;
+        let correlated_width = correlated.low + correlated.high;
+
+        assert!(
+            correlated_width < naive_width,
+            "correlated width {correlated_width} should be narrower than the naive (double-counted) width {naive_width}"
+        );
+    }
+
+    #[test]
+    fn sorting_does_not_panic_on_a_nan_producing_division() {
+        let nan_measure = Measure::scalar(0.0) / Measure::scalar(0.0);
+        assert!(nan_measure.value.is_nan());
+
+        let mut measures = [Measure::scalar(2.0), nan_measure, Measure::scalar(-1.0)];
+        measures.sort();
+
+        let finite: Vec<f64> = measures.iter().map(|m| m.value).filter(|v| !v.is_nan()).collect();
+        assert_eq!(finite, vec![-1.0, 2.0]);
+        assert_eq!(measures.iter().filter(|m| m.value.is_nan()).count(), 1);
+    }
+
+    #[test]
+    fn cmp_agrees_with_eq_on_negative_zero() {
+        let positive_zero = Measure::scalar(0.0);
+        let negative_zero = Measure::scalar(-0.0);
+
+        assert!(positive_zero == negative_zero);
+        assert_eq!(positive_zero.cmp(&negative_zero), Ordering::Equal);
+    }
+}
@@ -0,0 +1,174 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::measure::Measure;
+use crate::uncertainty::{Uncertainty, UncertaintyVariant};
+
+/// Error returned by `Measure`/`Uncertainty`'s `FromStr` impls, mirroring the
+/// `value Â± err` / `value +hi/-lo` grammar that `Display` produces. Only the
+/// value and its uncertainty are read: the `:= (low, high)` interval line that
+/// `Measure`'s `Display` appends is not accepted.
+#[derive(Debug)]
+pub struct ParseMeasureError(String);
+
+impl Display for ParseMeasureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid measurement literal: {}", self.0)
+    }
+}
+
+impl Error for ParseMeasureError {}
+
+fn parse_f64(s: &str) -> Result<f64, ParseMeasureError> {
+    let value = s
+        .trim()
+        .parse::<f64>()
+        .map_err(|e| ParseMeasureError(format!("{e} ({s:?})")))?;
+    if !value.is_finite() {
+        return Err(ParseMeasureError(format!(
+            "expected a finite number, got {s:?}"
+        )));
+    }
+    Ok(value)
+}
+
+fn fractional_digits(s: &str) -> usize {
+    match s.split_once('.') {
+        Some((_, frac)) => frac.chars().filter(|c| c.is_ascii_digit()).count(),
+        None => 0,
+    }
+}
+
+fn split_percent(s: &str) -> (&str, UncertaintyVariant) {
+    match s.trim().strip_suffix('%') {
+        Some(stripped) => (stripped.trim(), UncertaintyVariant::Relative),
+        None => (s.trim(), UncertaintyVariant::Absolute),
+    }
+}
+
+impl FromStr for Uncertainty {
+    type Err = ParseMeasureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Ok(Uncertainty::null());
+        }
+
+        if let Some(body) = s.strip_prefix('±').or_else(|| s.strip_prefix("Â±")) {
+            let (raw, variant) = split_percent(body);
+            let magnitude = parse_f64(raw)?;
+            let precision = fractional_digits(raw);
+            let uncertainty = match variant {
+                UncertaintyVariant::Relative => Uncertainty::symmetric_rel(magnitude),
+                UncertaintyVariant::Absolute => Uncertainty::symmetric_abs(magnitude),
+            };
+            return Ok(uncertainty.with_precision(precision));
+        }
+
+        if let Some(rest) = s.strip_prefix('+') {
+            let (hi, lo) = rest
+                .split_once('/')
+                .ok_or_else(|| ParseMeasureError(format!("expected +hi/-lo, got {s:?}")))?;
+            let lo = lo
+                .trim()
+                .strip_prefix('-')
+                .ok_or_else(|| ParseMeasureError(format!("expected +hi/-lo, got {s:?}")))?;
+            let (hi, hi_variant) = split_percent(hi);
+            let (lo, lo_variant) = split_percent(lo);
+            let variant = match (hi_variant, lo_variant) {
+                (UncertaintyVariant::Absolute, UncertaintyVariant::Absolute) => UncertaintyVariant::Absolute,
+                (UncertaintyVariant::Relative, UncertaintyVariant::Relative) => UncertaintyVariant::Relative,
+                _ => {
+                    return Err(ParseMeasureError(format!(
+                        "both legs of +hi/-lo must agree on %, got {s:?}"
+                    )))
+                }
+            };
+            let high = parse_f64(hi)?;
+            let low = parse_f64(lo)?;
+            let precision = fractional_digits(hi).max(fractional_digits(lo));
+            let uncertainty = Uncertainty {
+                variant,
+                ..Uncertainty::non_symmetric(low, high)
+            };
+            return Ok(uncertainty.with_precision(precision));
+        }
+
+        Err(ParseMeasureError(format!(
+            "unrecognised uncertainty literal: {s:?}"
+        )))
+    }
+}
+
+impl FromStr for Measure {
+    type Err = ParseMeasureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (value_part, rest) = match s.split_once(char::is_whitespace) {
+            Some((value_part, rest)) => (value_part, rest.trim()),
+            None => (s, ""),
+        };
+
+        let value = parse_f64(value_part)?;
+        let measure = Measure::scalar(value);
+
+        if rest.is_empty() {
+            let precision = fractional_digits(value_part);
+            return Ok(measure.with_precision(precision));
+        }
+
+        let uncertainty = rest.parse::<Uncertainty>()?;
+        Ok(measure.with_uncertainty(uncertainty))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_symmetric_absolute_literal() {
+        let measure: Measure = "1.23 ± 0.05".parse().unwrap();
+        assert_eq!(measure.value, 1.23);
+        assert_eq!(measure.uncertainty.low, 0.05);
+        assert_eq!(measure.uncertainty.high, 0.05);
+    }
+
+    #[test]
+    fn parses_asymmetric_literal() {
+        let measure: Measure = "1.23 +0.1/-0.2".parse().unwrap();
+        assert_eq!(measure.uncertainty.low, 0.2);
+        assert_eq!(measure.uncertainty.high, 0.1);
+    }
+
+    #[test]
+    fn parses_relative_percent_literal() {
+        let measure: Measure = "2.0 ± 50%".parse().unwrap();
+        assert!(matches!(
+            measure.uncertainty.variant,
+            UncertaintyVariant::Relative
+        ));
+        assert_eq!(measure.uncertainty.low, 50.0);
+    }
+
+    #[test]
+    fn parses_bare_scalar() {
+        let measure: Measure = "12".parse().unwrap();
+        assert_eq!(measure.value, 12.0);
+    }
+
+    #[test]
+    fn rejects_nan_instead_of_panicking() {
+        let result = "NaN ± 0.05".parse::<Measure>();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rejects_infinite_uncertainty() {
+        let result = "1.0 ± inf".parse::<Measure>();
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,226 @@
+use std::fmt::Display;
+
+pub const DEFAULT_PRECISION: usize = 5;
+
+#[derive(Copy, Clone, Debug)]
+pub enum UncertaintyVariant {
+    Absolute,
+    Relative,
+}
+
+/// How two `Uncertainty` values are merged when the `Measure`s carrying them
+/// are added/subtracted (absolute) or multiplied/divided (relative).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Combine {
+    /// Worst-case linear sum, `a + b`. The long-standing default.
+    Linear,
+    /// Root-sum-square combination, `sqrt(a^2 + b^2)`, for independent
+    /// errors combined the GUM/metrology way.
+    Quadrature,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Uncertainty {
+    pub low: f64,
+    pub high: f64,
+    pub precision: usize,
+    pub variant: UncertaintyVariant,
+    pub combine: Combine,
+}
+
+#[allow(dead_code)]
+impl Uncertainty {
+    pub fn null() -> Self {
+        Self {
+            low: 0.,
+            high: 0.,
+            precision: DEFAULT_PRECISION,
+            variant: UncertaintyVariant::Absolute,
+            combine: Combine::Linear,
+        }
+    }
+
+    pub fn with_combine(self, combine: Combine) -> Self {
+        Self { combine, ..self }
+    }
+
+    pub fn symmetric_abs(value: f64) -> Self {
+        Self {
+            low: value.abs(),
+            high: value.abs(),
+            variant: UncertaintyVariant::Absolute,
+            ..Self::null()
+        }
+    }
+    pub fn symmetric_rel(value: f64) -> Self {
+        Self {
+            low: value.abs(),
+            high: value.abs(),
+            variant: UncertaintyVariant::Relative,
+            ..Self::null()
+        }
+    }
+
+    pub fn non_symmetric(low: f64, high: f64) -> Self {
+        Self {
+            low,
+            high,
+            ..Self::null()
+        }
+    }
+
+    pub fn with_precision(self, precision: usize) -> Self {
+        Self { precision, ..self }
+    }
+
+    /// A zero-width uncertainty (e.g. `Uncertainty::null()`, or a constant
+    /// like `Measure::zero()`'s) contributes nothing to either combination
+    /// formula, so it shouldn't force a combine-policy mismatch.
+    fn is_null(&self) -> bool {
+        self.low == 0. && self.high == 0.
+    }
+
+    pub fn to_absolute(self, value: f64) -> Uncertainty {
+        if let UncertaintyVariant::Relative = self.variant {
+            Self {
+                low: (self.low / 100.) * value.abs(),
+                high: (self.high / 100.) * value.abs(),
+                variant: UncertaintyVariant::Absolute,
+                ..self
+            }
+        } else {
+            self
+        }
+    }
+    pub fn to_relative(self, value: f64) -> Uncertainty {
+        if let UncertaintyVariant::Absolute = self.variant {
+            Self {
+                low: (self.low - value.abs()).abs() / value.abs() * 100.,
+                high: (self.high - value.abs()).abs() / value.abs() * 100.,
+                variant: UncertaintyVariant::Relative,
+                ..self
+            }
+        } else {
+            self
+        }
+    }
+}
+
+impl Display for Uncertainty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.low != self.high {
+            write!(
+                f,
+                "+{:.precision$}{symbol} / -{:.precision$}{symbol}",
+                self.high,
+                self.low,
+                precision = self.precision,
+                symbol = match self.variant {
+                    UncertaintyVariant::Absolute => "",
+                    UncertaintyVariant::Relative => "%",
+                }
+            )
+        } else {
+            write!(
+                f,
+                "Â±{:.precision$}{symbol}",
+                self.high,
+                precision = self.precision,
+                symbol = match self.variant {
+                    UncertaintyVariant::Absolute => "",
+                    UncertaintyVariant::Relative => "%",
+                }
+            )
+        }
+    }
+}
+
+impl Default for Uncertainty {
+    fn default() -> Self {
+        Self::null()
+    }
+}
+
+impl std::ops::Add for Uncertainty {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        match (self.variant, rhs.variant) {
+            (UncertaintyVariant::Absolute, UncertaintyVariant::Absolute) => {
+                if self.combine != rhs.combine && !self.is_null() && !rhs.is_null() {
+                    panic!("Please use the same combination policy on both sides of additive propagation, {:?} + {:?}", self.combine, rhs.combine);
+                }
+                let combine = if self.is_null() { rhs.combine } else { self.combine };
+                let (low, high) = match combine {
+                    Combine::Linear => (self.low + rhs.low, self.high + rhs.high),
+                    Combine::Quadrature => (
+                        (self.low.powi(2) + rhs.low.powi(2)).sqrt(),
+                        (self.high.powi(2) + rhs.high.powi(2)).sqrt(),
+                    ),
+                };
+                Self {
+                    low,
+                    high,
+                    precision: self.precision.min(rhs.precision),
+                    variant: UncertaintyVariant::Absolute,
+                    combine,
+                }
+            }
+            (_, _) => panic!("Please convert both uncertainty to its absolute variants apply additive propagation, {:#?} + {:#?}", &self, &rhs),
+        }
+    }
+}
+
+impl std::ops::Mul for Uncertainty {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        match (self.variant, rhs.variant) {
+            (UncertaintyVariant::Relative, UncertaintyVariant::Relative) => {
+                if self.combine != rhs.combine && !self.is_null() && !rhs.is_null() {
+                    panic!("Please use the same combination policy on both sides of multiplicative propagation, {:?} * {:?}", self.combine, rhs.combine);
+                }
+                let combine = if self.is_null() { rhs.combine } else { self.combine };
+                let (low, high) = match combine {
+                    Combine::Linear => (self.low + rhs.low, self.high + rhs.high),
+                    Combine::Quadrature => (
+                        (self.low.powi(2) + rhs.low.powi(2)).sqrt(),
+                        (self.high.powi(2) + rhs.high.powi(2)).sqrt(),
+                    ),
+                };
+                Self {
+                    low,
+                    high,
+                    precision: self.precision.max(rhs.precision),
+                    variant: UncertaintyVariant::Relative,
+                    combine,
+                }
+            }
+            (_,_) => panic!("Please convert both uncertainty to its relative variant to apply multiplicative propagation")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quadrature_combines_with_a_null_uncertainty() {
+        let quadrature = Uncertainty::symmetric_abs(3.0).with_combine(Combine::Quadrature);
+        let combined = quadrature + Uncertainty::null();
+
+        assert_eq!(combined.low, 3.0);
+        assert_eq!(combined.high, 3.0);
+    }
+
+    #[test]
+    fn quadrature_add_combines_in_rss() {
+        let a = Uncertainty::symmetric_abs(3.0).with_combine(Combine::Quadrature);
+        let b = Uncertainty::symmetric_abs(4.0).with_combine(Combine::Quadrature);
+        let combined = a + b;
+
+        assert_eq!(combined.low, 5.0);
+        assert_eq!(combined.high, 5.0);
+    }
+}